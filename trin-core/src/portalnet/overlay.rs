@@ -1,10 +1,10 @@
-use crate::utils::xor_two_values;
+use crate::utils::content_id;
 
 use super::{
     discovery::Discovery,
     types::{
-        FindContent, FindNodes, FoundContent, Message, Nodes, Ping, Pong, ProtocolKind, Request,
-        Response, SszEnr,
+        Distance, FindContent, FindNodes, FoundContent, Message, Nodes, Ping, Pong, ProtocolKind,
+        Request, Response, SszEnr,
     },
     Enr, U256,
 };
@@ -168,6 +168,22 @@ impl OverlayProtocol {
         self.data_radius.read().await.clone()
     }
 
+    /// Returns whether the content key falls within the data radius of the node. This only
+    /// checks the radius and does not consider whether there is space to store the content.
+    pub async fn is_within_radius(&self, content_key: &[u8]) -> bool {
+        let node_id = self.local_enr().await.node_id();
+        within_radius(
+            &content_id(content_key),
+            &node_id.raw(),
+            &self.data_radius().await,
+        )
+    }
+
+    /// Returns the fraction of the 256-bit keyspace covered by the data radius of the node.
+    pub async fn keyspace_coverage(&self) -> f64 {
+        keyspace_fraction(&self.data_radius().await)
+    }
+
     /// Returns a vector of the ENRs of the closest nodes by the given log2 distances.
     pub async fn nodes_by_distance(&self, mut log2_distances: Vec<u64>) -> Vec<Enr> {
         let mut nodes_to_send = Vec::new();
@@ -197,18 +213,14 @@ impl OverlayProtocol {
     /// Returns list of nodes closer to content than self, sorted by distance.
     pub async fn find_nodes_close_to_content(&self, content_key: Vec<u8>) -> Vec<SszEnr> {
         let self_node_id = self.local_enr().await.node_id();
-        let self_distance = xor_two_values(&content_key, &self_node_id.raw().to_vec());
+        let content_id = content_id(&content_key);
+        let self_distance = Distance::from_xor(&content_id, &self_node_id.raw());
 
-        let mut nodes_with_distance: Vec<(Vec<u8>, Enr)> = self
+        let mut nodes_with_distance: Vec<(Distance, Enr)> = self
             .table_entries_enr()
             .await
             .into_iter()
-            .map(|enr| {
-                (
-                    xor_two_values(&content_key, &enr.node_id().raw().to_vec()),
-                    enr,
-                )
-            })
+            .map(|enr| (Distance::from_xor(&content_id, &enr.node_id().raw()), enr))
             .collect();
 
         nodes_with_distance.sort_by(|a, b| a.0.cmp(&b.0));
@@ -216,7 +228,7 @@ impl OverlayProtocol {
         let closest_nodes = nodes_with_distance
             .into_iter()
             .take(FIND_CONTENT_MAX_NODES)
-            .filter(|node_record| &node_record.0 < &self_distance)
+            .filter(|node_record| node_record.0 < self_distance)
             .map(|node_record| SszEnr::new(node_record.1))
             .collect();
 
@@ -301,3 +313,67 @@ impl OverlayProtocol {
             .await
     }
 }
+
+/// Returns whether the distance between the content-id and node id is strictly less than the
+/// radius.
+fn within_radius(content_id: &[u8; 32], node_id: &[u8; 32], radius: &U256) -> bool {
+    Distance::from_xor(content_id, node_id) < Distance::from(*radius)
+}
+
+/// Computes `radius / 2^256` as a float in [0, 1].
+fn keyspace_fraction(radius: &U256) -> f64 {
+    // U256 stores its value as little-endian 64-bit words.
+    let fraction: f64 = radius
+        .0
+        .iter()
+        .enumerate()
+        .map(|(i, word)| *word as f64 * 2f64.powi(64 * i as i32 - 256))
+        .sum();
+    fraction.min(1.0)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_keyspace_fraction_at_bounds() {
+        assert_eq!(keyspace_fraction(&U256::zero()), 0.0);
+        assert_eq!(keyspace_fraction(&U256::max_value()), 1.0);
+    }
+
+    #[test]
+    fn test_keyspace_fraction_midpoint() {
+        let mut half = [0u8; 32];
+        half[0] = 0x80;
+        assert_eq!(keyspace_fraction(&U256::from_big_endian(&half)), 0.5);
+    }
+
+    #[test]
+    fn test_within_radius_rejects_everything_at_zero_radius() {
+        let node_id = [0u8; 32];
+        assert!(!within_radius(&[0; 32], &node_id, &U256::zero()));
+        assert!(!within_radius(&[0xff; 32], &node_id, &U256::zero()));
+    }
+
+    #[test]
+    fn test_within_radius_rejects_distance_equal_to_radius() {
+        let node_id = [0u8; 32];
+        let mut content_id = [0u8; 32];
+        content_id[31] = 0x10;
+        assert!(!within_radius(
+            &content_id,
+            &node_id,
+            &U256::from_big_endian(&content_id)
+        ));
+    }
+
+    #[test]
+    fn test_within_radius_accepts_distance_just_inside_radius() {
+        let node_id = [0u8; 32];
+        let mut content_id = [0u8; 32];
+        content_id[31] = 0x10;
+        let radius = U256::from_big_endian(&content_id) + U256::one();
+        assert!(within_radius(&content_id, &node_id, &radius));
+    }
+}
@@ -345,6 +345,46 @@ impl FromStr for HexData {
     }
 }
 
+/// XOR distance between two 256-bit identifiers.
+///
+/// Ordering compares the full 256-bit value, so identifiers that only differ in
+/// their low-order bytes are not treated as equidistant.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub struct Distance(U256);
+
+impl Distance {
+    /// Interpret a big-endian XOR result (at most 32 bytes) as a distance.
+    pub fn from_big_endian(bytes: &[u8]) -> Self {
+        Distance(U256::from_big_endian(bytes))
+    }
+
+    /// Distance between two 256-bit identifiers, e.g. a node id and a content-id.
+    pub fn from_xor(first: &[u8; 32], second: &[u8; 32]) -> Self {
+        let mut xor = [0u8; 32];
+        for (i, byte) in xor.iter_mut().enumerate() {
+            *byte = first[i] ^ second[i];
+        }
+        Distance::from_big_endian(&xor)
+    }
+
+    /// Log2 distance as used for routing table buckets: 0 for identical values, otherwise
+    /// the position of the highest set bit of the XOR, in the range 1..=256.
+    pub fn log2(&self) -> u16 {
+        self.0.bits() as u16
+    }
+
+    /// Returns the distance as a 256-bit unsigned integer.
+    pub fn value(&self) -> U256 {
+        self.0
+    }
+}
+
+impl From<U256> for Distance {
+    fn from(value: U256) -> Self {
+        Distance(value)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -427,4 +467,78 @@ mod test {
         assert!(SszEnr(enr_one).eq(decoded.enrs.first().unwrap()));
         assert!(SszEnr(enr_two).eq(&decoded.enrs.into_iter().nth(1).unwrap()));
     }
+
+    #[test]
+    fn test_distance_orders_by_low_bytes() {
+        let mut near = [0u8; 32];
+        near[31] = 1;
+        let mut far = [0u8; 32];
+        far[31] = 2;
+        assert!(Distance::from_big_endian(&near) < Distance::from_big_endian(&far));
+    }
+
+    #[test]
+    fn test_distance_orders_across_full_width() {
+        let zero = Distance::from_xor(&[0; 32], &[0; 32]);
+        let mut low = [0u8; 32];
+        low[31] = 0xff;
+        let mut high = [0u8; 32];
+        high[0] = 0x01;
+        let mut high_and_low = high;
+        high_and_low[31] = 0x01;
+        let max = Distance::from_xor(&[0; 32], &[0xff; 32]);
+
+        let mut distances = vec![
+            max,
+            Distance::from_xor(&[0; 32], &high_and_low),
+            zero,
+            Distance::from_xor(&[0; 32], &high),
+            Distance::from_xor(&[0; 32], &low),
+        ];
+        distances.sort();
+
+        assert_eq!(
+            distances,
+            vec![
+                zero,
+                Distance::from_big_endian(&low),
+                Distance::from_big_endian(&high),
+                Distance::from_big_endian(&high_and_low),
+                max,
+            ]
+        );
+        assert_eq!(max.value(), U256::max_value());
+    }
+
+    #[test]
+    fn test_distance_from_xor_is_symmetric() {
+        let mut first = [0u8; 32];
+        first[0] = 0xf0;
+        first[31] = 0x0f;
+        let mut second = [0u8; 32];
+        second[0] = 0x0f;
+        second[31] = 0x0f;
+
+        let mut expected = [0u8; 32];
+        expected[0] = 0xff;
+
+        let distance = Distance::from_xor(&first, &second);
+        assert_eq!(distance, Distance::from_xor(&second, &first));
+        assert_eq!(distance, Distance::from_big_endian(&expected));
+    }
+
+    #[test]
+    fn test_distance_log2() {
+        assert_eq!(Distance::from_xor(&[0; 32], &[0; 32]).log2(), 0);
+
+        let mut lowest_bit = [0u8; 32];
+        lowest_bit[31] = 0x01;
+        assert_eq!(Distance::from_big_endian(&lowest_bit).log2(), 1);
+
+        let mut second_byte = [0u8; 32];
+        second_byte[30] = 0x80;
+        assert_eq!(Distance::from_big_endian(&second_byte).log2(), 16);
+
+        assert_eq!(Distance::from_xor(&[0; 32], &[0xff; 32]).log2(), 256);
+    }
 }
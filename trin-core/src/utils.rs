@@ -1,6 +1,8 @@
+use crate::portalnet::types::Distance;
 use directories::ProjectDirs;
 use discv5::enr::NodeId;
 use rocksdb::{Options, DB};
+use sha2::{Digest, Sha256};
 use std::{env, fs};
 
 const TRIN_DATA_ENV_VAR: &str = "TRIN_DATA_PATH";
@@ -17,6 +19,20 @@ pub fn xor_two_values(first: &[u8], second: &[u8]) -> Vec<u8> {
         .collect()
 }
 
+/// Returns the XOR distance between two 256-bit identifiers, such as two node ids or a node id
+/// and a content-id.
+pub fn distance_between(first: &[u8; 32], second: &[u8; 32]) -> Distance {
+    Distance::from_xor(first, second)
+}
+
+/// Derive the content-id for a content key, which is the sha256 hash of the key bytes.
+/// Distances to content are measured against the content-id, never the raw key.
+pub fn content_id(content_key: &[u8]) -> [u8; 32] {
+    let mut content_id = [0u8; 32];
+    content_id.copy_from_slice(&Sha256::digest(content_key));
+    content_id
+}
+
 pub fn get_data_dir(node_id: NodeId) -> String {
     let path = env::var(TRIN_DATA_ENV_VAR).unwrap_or_else(|_| get_default_data_dir(node_id));
 
@@ -73,4 +89,86 @@ mod test {
         let two = vec![0, 0, 1];
         xor_two_values(&one, &two);
     }
+
+    #[test]
+    fn test_content_id() {
+        let content_key =
+            hex::decode("00d1c390624d3bd4e409a61a858e5dcc5517729a9170d014a6c96530d64dd8621d")
+                .unwrap();
+        assert_eq!(
+            hex::encode(content_id(&content_key)),
+            "3e86b3767b57402ea72e369ae0496ce47cc15be685bec3b4726b9f316e3895fe"
+        );
+    }
+
+    #[test]
+    fn test_content_id_distance_for_keys_of_any_length() {
+        // With the zero node id, the distance to a key is its content-id.
+        let node_id = [0u8; 32];
+        let empty_key: Vec<u8> = vec![];
+        let short_key = vec![1];
+        let two_byte_key = vec![1, 2];
+        let full_width_key = vec![1; 32];
+        let long_key = vec![1; 64];
+
+        let distance = |content_key: &[u8]| Distance::from_xor(&content_id(content_key), &node_id);
+
+        for content_key in [
+            &empty_key,
+            &short_key,
+            &two_byte_key,
+            &full_width_key,
+            &long_key,
+        ] {
+            assert_eq!(
+                distance(content_key),
+                Distance::from_big_endian(&content_id(content_key))
+            );
+        }
+        assert_eq!(
+            distance(&empty_key),
+            Distance::from_big_endian(
+                &hex::decode("e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855")
+                    .unwrap()
+            )
+        );
+
+        // Content-ids: 0x4bf5.., 0x72cd.., 0x7c89.., 0xa128.., 0xe3b0..
+        assert!(distance(&short_key) < distance(&full_width_key));
+        assert!(distance(&full_width_key) < distance(&long_key));
+        assert!(distance(&long_key) < distance(&two_byte_key));
+        assert!(distance(&two_byte_key) < distance(&empty_key));
+    }
+
+    #[test]
+    fn test_distance_between() {
+        let mut first = [0u8; 32];
+        first[0] = 0b1010_0000;
+        first[31] = 0b0000_0011;
+        let mut second = [0u8; 32];
+        second[0] = 0b0110_0000;
+        second[31] = 0b0000_0101;
+
+        let mut expected = [0u8; 32];
+        expected[0] = 0b1100_0000;
+        expected[31] = 0b0000_0110;
+
+        assert_eq!(
+            distance_between(&first, &second),
+            Distance::from_big_endian(&expected)
+        );
+        assert_eq!(
+            distance_between(&first, &second),
+            distance_between(&second, &first)
+        );
+    }
+
+    #[test]
+    fn test_distance_between_identical_ids_is_zero() {
+        let id = [0x5a; 32];
+        assert_eq!(
+            distance_between(&id, &id),
+            Distance::from_big_endian(&[0; 32])
+        );
+    }
 }
@@ -0,0 +1,166 @@
+use ethereum_types::H256;
+use ssz::{Decode, DecodeError, Encode};
+use trin_core::utils::content_id;
+
+/// Length in bytes of an encoded history content key: a selector byte followed by a hash.
+const CONTENT_KEY_LENGTH: usize = 33;
+
+/// A content key in the history network.
+///
+/// Encoded as an SSZ union: a one byte selector followed by a container holding the hash that
+/// identifies the content.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum HistoryContentKey {
+    /// Header of the block with the given hash.
+    BlockHeader(H256),
+    /// Body (transactions and uncles) of the block with the given hash.
+    BlockBody(H256),
+    /// Receipts of the block with the given hash.
+    BlockReceipts(H256),
+    /// Epoch header accumulator with the given hash.
+    EpochAccumulator(H256),
+}
+
+impl HistoryContentKey {
+    fn selector(&self) -> u8 {
+        match self {
+            HistoryContentKey::BlockHeader(_) => 0,
+            HistoryContentKey::BlockBody(_) => 1,
+            HistoryContentKey::BlockReceipts(_) => 2,
+            HistoryContentKey::EpochAccumulator(_) => 3,
+        }
+    }
+
+    fn hash(&self) -> &H256 {
+        match self {
+            HistoryContentKey::BlockHeader(hash)
+            | HistoryContentKey::BlockBody(hash)
+            | HistoryContentKey::BlockReceipts(hash)
+            | HistoryContentKey::EpochAccumulator(hash) => hash,
+        }
+    }
+
+    /// Return the SSZ encoding of the content key.
+    pub fn encode(&self) -> Vec<u8> {
+        self.as_ssz_bytes()
+    }
+
+    /// Decode a content key from its SSZ encoding.
+    pub fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+        Self::from_ssz_bytes(bytes)
+    }
+
+    /// Return the content-id of the content key, the sha256 hash of its encoding.
+    pub fn content_id(&self) -> [u8; 32] {
+        content_id(&self.encode())
+    }
+}
+
+impl ssz::Encode for HistoryContentKey {
+    fn is_ssz_fixed_len() -> bool {
+        false
+    }
+
+    fn ssz_append(&self, buf: &mut Vec<u8>) {
+        buf.push(self.selector());
+        buf.extend_from_slice(self.hash().as_bytes());
+    }
+}
+
+impl ssz::Decode for HistoryContentKey {
+    fn is_ssz_fixed_len() -> bool {
+        false
+    }
+
+    fn from_ssz_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
+        if bytes.len() != CONTENT_KEY_LENGTH {
+            return Err(DecodeError::InvalidByteLength {
+                len: bytes.len(),
+                expected: CONTENT_KEY_LENGTH,
+            });
+        }
+
+        let hash = H256::from_slice(&bytes[1..]);
+        match bytes[0] {
+            0 => Ok(HistoryContentKey::BlockHeader(hash)),
+            1 => Ok(HistoryContentKey::BlockBody(hash)),
+            2 => Ok(HistoryContentKey::BlockReceipts(hash)),
+            3 => Ok(HistoryContentKey::EpochAccumulator(hash)),
+            selector => Err(DecodeError::BytesInvalid(format!(
+                "Unknown history content key selector: {}",
+                selector
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const BLOCK_HASH: &str = "d1c390624d3bd4e409a61a858e5dcc5517729a9170d014a6c96530d64dd8621d";
+    const EPOCH_HASH: &str = "e242814b90ed3950e13aac7e56ce116540c71b41d1516605aada26c6c07cc491";
+
+    fn hash(hex_str: &str) -> H256 {
+        H256::from_slice(&hex::decode(hex_str).unwrap())
+    }
+
+    fn assert_content_key(content_key: HistoryContentKey, encoded: &str, content_id: &str) {
+        assert_eq!(hex::encode(content_key.encode()), encoded);
+        assert_eq!(hex::encode(content_key.content_id()), content_id);
+        assert_eq!(
+            HistoryContentKey::decode(&hex::decode(encoded).unwrap()).unwrap(),
+            content_key
+        );
+    }
+
+    #[test]
+    fn test_block_header() {
+        assert_content_key(
+            HistoryContentKey::BlockHeader(hash(BLOCK_HASH)),
+            "00d1c390624d3bd4e409a61a858e5dcc5517729a9170d014a6c96530d64dd8621d",
+            "3e86b3767b57402ea72e369ae0496ce47cc15be685bec3b4726b9f316e3895fe",
+        );
+    }
+
+    #[test]
+    fn test_block_body() {
+        assert_content_key(
+            HistoryContentKey::BlockBody(hash(BLOCK_HASH)),
+            "01d1c390624d3bd4e409a61a858e5dcc5517729a9170d014a6c96530d64dd8621d",
+            "ebe414854629d60c58ddd5bf60fd72e41760a5f7a463fdcb169f13ee4a26786b",
+        );
+    }
+
+    #[test]
+    fn test_block_receipts() {
+        assert_content_key(
+            HistoryContentKey::BlockReceipts(hash(BLOCK_HASH)),
+            "02d1c390624d3bd4e409a61a858e5dcc5517729a9170d014a6c96530d64dd8621d",
+            "a888f4aafe9109d495ac4d4774a6277c1ada42035e3da5e10a04cc93247c04a4",
+        );
+    }
+
+    #[test]
+    fn test_epoch_accumulator() {
+        assert_content_key(
+            HistoryContentKey::EpochAccumulator(hash(EPOCH_HASH)),
+            "03e242814b90ed3950e13aac7e56ce116540c71b41d1516605aada26c6c07cc491",
+            "9fb2175e76c6989e0fdac3ee10c40d2a81eb176af32e1c16193e3904fe56896e",
+        );
+    }
+
+    #[test]
+    fn test_decode_rejects_unknown_selector() {
+        let mut bytes = vec![4];
+        bytes.extend_from_slice(&hex::decode(BLOCK_HASH).unwrap());
+        assert!(HistoryContentKey::decode(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_decode_rejects_wrong_length() {
+        assert!(HistoryContentKey::decode(&[]).is_err());
+        assert!(HistoryContentKey::decode(&[0; 32]).is_err());
+        assert!(HistoryContentKey::decode(&[0; 34]).is_err());
+    }
+}
@@ -1,3 +1,4 @@
+pub mod content_key;
 pub mod events;
 mod jsonrpc;
 pub mod network;